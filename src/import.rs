@@ -0,0 +1,68 @@
+/// Defines an import (a single `use path::Type;` entry).
+#[derive(Debug, Clone)]
+pub struct Import {
+    /// the path being imported from
+    pub(crate) path: String,
+
+    /// the type being imported, or `*` for a glob import
+    pub(crate) ty: String,
+
+    /// an optional `as` alias for the imported type
+    rename: Option<String>,
+
+    /// whether this is a glob import (`use path::*;`)
+    glob: bool,
+
+    /// the visibility of the `use` statement
+    pub(crate) vis: Option<String>,
+}
+
+impl Import {
+    /// Returns a new import.
+    pub fn new(path: &str, ty: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            ty: ty.to_string(),
+            rename: None,
+            glob: false,
+            vis: None,
+        }
+    }
+
+    /// Returns a new glob import, e.g. `use path::*;`
+    pub(crate) fn new_glob(path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            ty: "*".to_string(),
+            rename: None,
+            glob: true,
+            vis: None,
+        }
+    }
+
+    /// Set the import's visibility.
+    pub fn vis(&mut self, vis: &str) -> &mut Self {
+        self.vis = Some(vis.to_string());
+        self
+    }
+
+    /// Rename the imported type with an `as` alias, e.g. `use path::X as Y;`
+    pub fn rename(&mut self, alias: &str) -> &mut Self {
+        self.rename = Some(alias.to_string());
+        self
+    }
+
+    /// Returns whether this is a glob import.
+    pub(crate) fn is_glob(&self) -> bool {
+        self.glob
+    }
+
+    /// Returns the fragment to place after the `use path::` prefix, e.g.
+    /// `Foo` or `Foo as Bar`.
+    pub(crate) fn item(&self) -> String {
+        match &self.rename {
+            Some(rename) => format!("{} as {}", self.ty, rename),
+            None => self.ty.clone(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::comment::Comment;
+use crate::consts::Const;
+use crate::function::Function;
+use crate::module::Module;
+use crate::r#enum::Enum;
+use crate::r#impl::Impl;
+use crate::r#struct::Struct;
+use crate::r#trait::Trait;
+use crate::type_alias::TypeAlias;
+
+/// An item that can be pushed into a `Scope` or `Module`.
+#[derive(Debug, Clone)]
+pub enum Item {
+    Module(Module),
+    Struct(Struct),
+    Function(Function),
+    Trait(Trait),
+    Enum(Enum),
+    Impl(Impl),
+    TypeAlias(TypeAlias),
+    Const(Const),
+    Comment(Comment),
+    Raw(String),
+}
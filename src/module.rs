@@ -0,0 +1,226 @@
+use std::fmt::{self, Write};
+
+use crate::comment::Comment;
+use crate::consts::Const;
+use crate::docs::Docs;
+use crate::formatter::Formatter;
+use crate::function::Function;
+use crate::item::Item;
+use crate::r#enum::Enum;
+use crate::r#impl::Impl;
+use crate::r#struct::Struct;
+use crate::r#trait::Trait;
+use crate::type_alias::TypeAlias;
+
+/// Defines a module.
+#[derive(Debug, Clone)]
+pub struct Module {
+    /// Module name
+    pub name: String,
+
+    /// Module visibility
+    vis: Option<String>,
+
+    /// Module documentation
+    docs: Option<Docs>,
+
+    /// Contents of the module
+    items: Vec<Item>,
+}
+
+impl Module {
+    /// Returns a new, blank module
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            vis: None,
+            docs: None,
+            items: Vec::new(),
+        }
+    }
+
+    /// Set the module's visibility
+    pub fn vis(&mut self, vis: &str) -> &mut Self {
+        self.vis = Some(vis.to_string());
+        self
+    }
+
+    /// Add documentation to the module
+    pub fn doc(&mut self, docs: Docs) -> &mut Self {
+        self.docs = Some(docs);
+        self
+    }
+
+    /// Push a new cost definition, returning a mutable reference to it.
+    pub fn new_const(&mut self, name: &str, ty: &str, value: &str) -> &mut Const {
+        self.push_const(Const::new(name, ty, value));
+
+        match self.items.last_mut().unwrap() {
+            Item::Const(v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a const definition
+    pub fn push_const(&mut self, item: Const) -> &mut Self {
+        self.items.push(Item::Const(item));
+        self
+    }
+
+    /// Push a new struct definition, returning a mutable reference to it.
+    pub fn new_struct(&mut self, name: &str) -> &mut Struct {
+        self.push_struct(Struct::new(name));
+
+        match self.items.last_mut().unwrap() {
+            Item::Struct(v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a struct definition
+    pub fn push_struct(&mut self, item: Struct) -> &mut Self {
+        self.items.push(Item::Struct(item));
+        self
+    }
+
+    /// Push a new function definition, returning a mutable reference to it.
+    pub fn new_fn(&mut self, name: &str) -> &mut Function {
+        self.push_fn(Function::new(name));
+
+        match *self.items.last_mut().unwrap() {
+            Item::Function(ref mut v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a function definition
+    pub fn push_fn(&mut self, item: Function) -> &mut Self {
+        self.items.push(Item::Function(item));
+        self
+    }
+
+    /// Push a new trait definition, returning a mutable reference to it.
+    pub fn new_trait(&mut self, name: &str) -> &mut Trait {
+        self.push_trait(Trait::new(name));
+
+        match *self.items.last_mut().unwrap() {
+            Item::Trait(ref mut v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a trait definition
+    pub fn push_trait(&mut self, item: Trait) -> &mut Self {
+        self.items.push(Item::Trait(item));
+        self
+    }
+
+    /// Push a new enum definition, returning a mutable reference to it.
+    pub fn new_enum(&mut self, name: &str) -> &mut Enum {
+        self.push_enum(Enum::new(name));
+
+        match *self.items.last_mut().unwrap() {
+            Item::Enum(ref mut v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push an enum definition
+    pub fn push_enum(&mut self, item: Enum) -> &mut Self {
+        self.items.push(Item::Enum(item));
+        self
+    }
+
+    /// Push a new `impl` block, returning a mutable reference to it.
+    pub fn new_impl(&mut self, target: &str) -> &mut Impl {
+        self.push_impl(Impl::new(target));
+
+        match *self.items.last_mut().unwrap() {
+            Item::Impl(ref mut v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push an `impl` block.
+    pub fn push_impl(&mut self, item: Impl) -> &mut Self {
+        self.items.push(Item::Impl(item));
+        self
+    }
+
+    /// Push a new type alias definition, returning a mutable reference to it.
+    pub fn new_type_alias(&mut self, name: &str, target: &str) -> &mut TypeAlias {
+        self.push_type_alias(TypeAlias::new(name, target));
+
+        match self.items.last_mut().unwrap() {
+            Item::TypeAlias(v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a type alias definition
+    pub fn push_type_alias(&mut self, item: TypeAlias) -> &mut Self {
+        self.items.push(Item::TypeAlias(item));
+        self
+    }
+
+    /// Push a new comment, returning a mutable reference to it.
+    pub fn new_comment(&mut self, comment: &str) -> &mut Comment {
+        self.push_comment(Comment::new(comment));
+
+        match *self.items.last_mut().unwrap() {
+            Item::Comment(ref mut v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a comment
+    pub fn push_comment(&mut self, comment: Comment) -> &mut Self {
+        self.items.push(Item::Comment(comment));
+        self
+    }
+
+    /// Push a raw string to the module.
+    ///
+    /// This string will be included verbatim in the formatted string.
+    pub fn raw(&mut self, val: &str) -> &mut Self {
+        self.items.push(Item::Raw(val.to_string()));
+        self
+    }
+
+    /// Formats the module using the given formatter.
+    pub fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        self.docs.as_ref().map(|d| d.fmt(fmt));
+
+        match &self.vis {
+            Some(v) => writeln!(fmt, "{v} mod {} {{", self.name)?,
+            None => writeln!(fmt, "mod {} {{", self.name)?,
+        }
+
+        fmt.indent(|fmt| {
+            for (i, item) in self.items.iter().enumerate() {
+                if i != 0 {
+                    writeln!(fmt)?;
+                }
+
+                match item {
+                    Item::Module(v) => v.fmt(fmt)?,
+                    Item::Const(v) => v.fmt(fmt)?,
+                    Item::Struct(v) => v.fmt(fmt)?,
+                    Item::Function(v) => v.fmt(false, fmt)?,
+                    Item::Trait(v) => v.fmt(fmt)?,
+                    Item::Enum(v) => v.fmt(fmt)?,
+                    Item::Impl(v) => v.fmt(fmt)?,
+                    Item::TypeAlias(v) => v.fmt(fmt)?,
+                    Item::Comment(v) => v.fmt(fmt)?,
+                    Item::Raw(v) => writeln!(fmt, "{v}")?,
+                }
+            }
+
+            Ok(())
+        })?;
+
+        writeln!(fmt, "}}")?;
+
+        Ok(())
+    }
+}
@@ -11,6 +11,7 @@ use crate::import::Import;
 use crate::item::Item;
 use crate::license::License;
 use crate::module::Module;
+use crate::type_alias::TypeAlias;
 
 use crate::r#enum::Enum;
 use crate::r#impl::Impl;
@@ -73,6 +74,18 @@ impl Scope {
             .or_insert_with(|| Import::new(path, ty))
     }
 
+    /// Import everything from a path into the scope via a glob import.
+    ///
+    /// This results in a new `use path::*;` statement being added to the
+    /// beginning of the scope.
+    pub fn import_glob(&mut self, path: &str) -> &mut Import {
+        self.imports
+            .entry(path.to_string())
+            .or_default()
+            .entry("*".to_string())
+            .or_insert_with(|| Import::new_glob(path))
+    }
+
     /// Push a new cost definition, returning a mutable reference to it.
     pub fn new_const(&mut self, name: &str, ty: &str, value: &str) -> &mut Const {
         self.push_const(Const::new(name, ty, value));
@@ -230,6 +243,22 @@ impl Scope {
         self
     }
 
+    /// Push a new type alias definition, returning a mutable reference to it.
+    pub fn new_type_alias(&mut self, name: &str, target: &str) -> &mut TypeAlias {
+        self.push_type_alias(TypeAlias::new(name, target));
+
+        match self.items.last_mut().unwrap() {
+            Item::TypeAlias(v) => v,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Push a type alias definition
+    pub fn push_type_alias(&mut self, item: TypeAlias) -> &mut Self {
+        self.items.push(Item::TypeAlias(item));
+        self
+    }
+
     /// Push a new comment, returning a mutable reference to it.
     pub fn new_comment(&mut self, comment: &str) -> &mut Comment {
         self.push_comment(Comment::new(comment));
@@ -296,6 +325,7 @@ impl Scope {
                 Item::Trait(v) => v.fmt(fmt)?,
                 Item::Enum(v) => v.fmt(fmt)?,
                 Item::Impl(v) => v.fmt(fmt)?,
+                Item::TypeAlias(v) => v.fmt(fmt)?,
                 Item::Raw(v) => {
                     writeln!(fmt, "{v}")?;
                 }
@@ -317,39 +347,51 @@ impl Scope {
             }
         }
 
-        let mut tys = vec![];
+        let mut named = vec![];
 
         // Loop over all visibilities and format the associated imports
         for vis in &visibilities {
             for (path, imports) in &self.imports {
-                tys.clear();
+                // Glob imports can't be coalesced into a braced group, so
+                // each one gets its own `use path::*;` line.
+                for import in imports.values() {
+                    if import.is_glob() && import.vis == *vis {
+                        if let Some(vis) = &vis {
+                            write!(fmt, "{vis} ")?;
+                        }
+
+                        writeln!(fmt, "use {path}::*;")?;
+                    }
+                }
+
+                named.clear();
 
-                for (ty, import) in imports {
-                    if *vis == import.vis {
-                        tys.push(ty);
+                for import in imports.values() {
+                    if !import.is_glob() && import.vis == *vis {
+                        named.push(import);
                     }
                 }
 
-                if !tys.is_empty() {
+                if !named.is_empty() {
                     if let Some(vis) = &vis {
                         write!(fmt, "{vis} ")?;
                     }
 
                     write!(fmt, "use {path}::")?;
 
-                    match tys.len() {
+                    match named.len() {
                         0 => {}
                         1 => {
-                            writeln!(fmt, "{};", tys[0])?;
+                            writeln!(fmt, "{};", named[0].item())?;
                         }
                         _ => {
                             write!(fmt, "{{")?;
 
-                            for (i, ty) in tys.iter().enumerate() {
+                            for (i, import) in named.iter().enumerate() {
                                 if i != 0 {
                                     write!(fmt, ", ")?;
                                 }
-                                write!(fmt, "{ty}")?;
+                                write!(fmt, "{}", import.item())?;
                             }
 
                             writeln!(fmt, "}};")?;
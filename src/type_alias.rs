@@ -0,0 +1,107 @@
+use std::fmt::{self, Write};
+
+use crate::formatter::Formatter;
+use crate::r#type::Type;
+
+/// Defines a `type` alias, e.g. `pub type NodeMap<K> = IndexMap<K, Node>;`
+#[derive(Debug, Clone)]
+pub struct TypeAlias {
+    /// Alias name
+    pub name: String,
+
+    /// Aliased type
+    pub target: Type,
+
+    /// Alias visibility
+    pub vis: Option<String>,
+
+    /// Alias documentation
+    pub documentation: Vec<String>,
+
+    /// Generic parameters, e.g. `K` in `type NodeMap<K> = ...`
+    pub generics: Vec<String>,
+
+    /// `where` bounds, as raw clauses such as `K: Eq + Hash`
+    pub bounds: Vec<String>,
+}
+
+impl TypeAlias {
+    /// Returns a type alias with the provided name and target type
+    pub fn new<T>(name: &str, target: T) -> Self
+    where
+        T: Into<Type>,
+    {
+        Self {
+            name: name.to_string(),
+            target: target.into(),
+            vis: None,
+            documentation: Vec::new(),
+            generics: Vec::new(),
+            bounds: Vec::new(),
+        }
+    }
+
+    /// Set the alias's documentation.
+    pub fn doc(&mut self, documentation: Vec<&str>) -> &mut Self {
+        self.documentation = documentation.iter().map(|doc| doc.to_string()).collect();
+        self
+    }
+
+    /// Set the alias's visibility
+    pub fn vis(&mut self, vis: &str) -> &mut Self {
+        self.vis = Some(vis.to_string());
+        self
+    }
+
+    /// Add a generic parameter, e.g. `K`
+    pub fn generic(&mut self, name: &str) -> &mut Self {
+        self.generics.push(name.to_string());
+        self
+    }
+
+    /// Add a `where` bound, e.g. `K: Eq + Hash`
+    pub fn bound(&mut self, bound: &str) -> &mut Self {
+        self.bounds.push(bound.to_string());
+        self
+    }
+
+    /// Formats the type alias using the given formatter.
+    pub fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for doc in &self.documentation {
+            writeln!(fmt, "/// {doc}")?;
+        }
+
+        match &self.vis {
+            Some(v) => write!(fmt, "{v} type {}", self.name),
+            None => write!(fmt, "type {}", self.name),
+        }?;
+
+        if !self.generics.is_empty() {
+            write!(fmt, "<")?;
+            for (i, generic) in self.generics.iter().enumerate() {
+                if i != 0 {
+                    write!(fmt, ", ")?;
+                }
+                write!(fmt, "{generic}")?;
+            }
+            write!(fmt, ">")?;
+        }
+
+        write!(fmt, " = ")?;
+        self.target.fmt(fmt)?;
+
+        if !self.bounds.is_empty() {
+            write!(fmt, "\nwhere")?;
+            for (i, bound) in self.bounds.iter().enumerate() {
+                if i != 0 {
+                    write!(fmt, ",")?;
+                }
+                write!(fmt, "\n    {bound}")?;
+            }
+        }
+
+        writeln!(fmt, ";")?;
+
+        Ok(())
+    }
+}